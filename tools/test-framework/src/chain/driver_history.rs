@@ -0,0 +1,252 @@
+/*!
+   [`ChainDriver`] methods for querying a wallet's paginated transfer
+   history.
+*/
+
+use ibc_relayer::util::block_on;
+use tendermint_rpc::endpoint::tx_search::Response as TxSearchResponse;
+use tendermint_rpc::query::Query;
+use tendermint_rpc::{Client, HttpClient, Order};
+
+use crate::chain::driver::ChainDriver;
+use crate::error::Error;
+use crate::ibc::denom::Denom;
+use crate::types::wallet::WalletAddress;
+use crate::types::wallet_history::{WalletHistoryCursor, WalletHistoryPage, WalletTransfer};
+
+impl ChainDriver {
+    /**
+       Query for a page of `wallet_address`'s transfer history, covering
+       both sent and received transfers, decoded into [`WalletTransfer`]s.
+
+       Runs separate `tx_search` queries for `transfer.sender` and
+       `transfer.recipient` with `page_size` transactions per query, and
+       merges the results by descending height, so a page can hold more
+       than `page_size` transfers. `cursor` (from a previous page's
+       [`WalletHistoryPage::next_cursor`]) continues from the next
+       `tx_search` page instead of re-fetching from the start.
+    */
+    pub fn query_wallet_history(
+        &self,
+        wallet_address: &WalletAddress,
+        page_size: u32,
+        cursor: Option<WalletHistoryCursor>,
+    ) -> Result<WalletHistoryPage, Error> {
+        let client = HttpClient::new(self.rpc_listen_address().as_str()).map_err(Error::tendermint_rpc)?;
+
+        let page_number = match &cursor {
+            Some(cursor) => cursor
+                .0
+                .parse::<u32>()
+                .map_err(|_| Error::invalid_wallet_history_cursor(cursor.0.clone()))?,
+            None => 1,
+        };
+
+        let sent = block_on(search_transfers(
+            &client,
+            Query::eq("transfer.sender", wallet_address.0.as_str()),
+            wallet_address.0.as_str(),
+            Direction::Sent,
+            page_number,
+            page_size,
+        ))?;
+
+        let received = block_on(search_transfers(
+            &client,
+            Query::eq("transfer.recipient", wallet_address.0.as_str()),
+            wallet_address.0.as_str(),
+            Direction::Received,
+            page_number,
+            page_size,
+        ))?;
+
+        let mut transfers = sent.transfers;
+        transfers.extend(received.transfers);
+        transfers.sort_by(|a, b| b.height.cmp(&a.height));
+
+        let has_more = sent.total > page_number * page_size || received.total > page_number * page_size;
+
+        let next_cursor = if has_more {
+            Some(WalletHistoryCursor((page_number + 1).to_string()))
+        } else {
+            None
+        };
+
+        Ok(WalletHistoryPage {
+            transfers,
+            next_cursor,
+        })
+    }
+}
+
+/**
+   Which side of a `transfer` event this page's wallet played, which
+   determines which attribute is the *other* party (the `counterparty`
+   of the decoded [`WalletTransfer`]).
+*/
+#[derive(Clone, Copy)]
+enum Direction {
+    /// Queried via `transfer.sender`: the counterparty is the recipient.
+    Sent,
+    /// Queried via `transfer.recipient`: the counterparty is the sender.
+    Received,
+}
+
+struct RawTransferPage {
+    transfers: Vec<WalletTransfer>,
+    total: u32,
+}
+
+async fn search_transfers(
+    client: &HttpClient,
+    query: Query,
+    wallet_address: &str,
+    direction: Direction,
+    page: u32,
+    per_page: u32,
+) -> Result<RawTransferPage, Error> {
+    // A matching tx can carry other `transfer` events (e.g. the fee
+    // payment) in which the wallet is not on the queried side.
+    let wallet_key = match direction {
+        Direction::Sent => "sender",
+        Direction::Received => "recipient",
+    };
+
+    let response: TxSearchResponse = client
+        .tx_search(query, false, page, per_page as u8, Order::Descending)
+        .await
+        .map_err(Error::tendermint_rpc)?;
+
+    let transfers = response
+        .txs
+        .iter()
+        .flat_map(|tx| {
+            let tx_hash = tx.hash.to_string();
+
+            tx.tx_result
+                .events
+                .iter()
+                .filter(move |event| {
+                    event.kind == "transfer"
+                        && event.attributes.iter().any(|attribute| {
+                            attribute.key.as_ref() == wallet_key && attribute.value.as_ref() == wallet_address
+                        })
+                })
+                .filter_map(move |event| decode_transfer_event(event, tx.height.value(), tx_hash.clone(), direction))
+        })
+        .collect();
+
+    Ok(RawTransferPage {
+        transfers,
+        total: response.total_count,
+    })
+}
+
+fn decode_transfer_event(
+    event: &tendermint::abci::Event,
+    height: u64,
+    tx_hash: String,
+    direction: Direction,
+) -> Option<WalletTransfer> {
+    let mut sender = None;
+    let mut recipient = None;
+    let mut amount = None;
+    let mut denom = None;
+
+    for attribute in &event.attributes {
+        match attribute.key.as_ref() {
+            "sender" => sender = Some(attribute.value.to_string()),
+            "recipient" => recipient = Some(attribute.value.to_string()),
+            "amount" => {
+                let (parsed_amount, parsed_denom) = split_amount_denom(attribute.value.as_ref())?;
+                amount = Some(parsed_amount);
+                denom = Some(parsed_denom);
+            }
+            _ => {}
+        }
+    }
+
+    let counterparty = match direction {
+        Direction::Sent => recipient?,
+        Direction::Received => sender?,
+    };
+
+    Some(WalletTransfer {
+        height,
+        tx_hash,
+        counterparty: WalletAddress(counterparty),
+        denom: Denom::base(&denom?),
+        amount: amount?,
+    })
+}
+
+/**
+   Splits a Cosmos SDK `"1000uatom"`-style amount string into its numeric
+   amount and denom.
+*/
+fn split_amount_denom(raw: &str) -> Option<(u64, String)> {
+    let split_at = raw.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, denom) = raw.split_at(split_at);
+    Some((amount.parse().ok()?, denom.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tendermint::abci::{Event, EventAttribute};
+
+    fn transfer_event(sender: &str, recipient: &str, amount: &str) -> Event {
+        Event {
+            kind: "transfer".to_string(),
+            attributes: vec![
+                EventAttribute {
+                    key: "sender".to_string().into(),
+                    value: sender.to_string().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "recipient".to_string().into(),
+                    value: recipient.to_string().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "amount".to_string().into(),
+                    value: amount.to_string().into(),
+                    index: false,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn sent_direction_uses_recipient_as_counterparty() {
+        let event = transfer_event("wallet-a", "wallet-b", "1000uatom");
+        let transfer = decode_transfer_event(&event, 42, "HASH".to_string(), Direction::Sent).unwrap();
+
+        assert_eq!(transfer.counterparty.0, "wallet-b");
+        assert_eq!(transfer.amount, 1000);
+        assert_eq!(transfer.tx_hash, "HASH");
+    }
+
+    #[test]
+    fn received_direction_uses_sender_as_counterparty() {
+        let event = transfer_event("wallet-a", "wallet-b", "1000uatom");
+        let transfer = decode_transfer_event(&event, 42, "HASH".to_string(), Direction::Received).unwrap();
+
+        assert_eq!(transfer.counterparty.0, "wallet-a");
+        assert_eq!(transfer.amount, 1000);
+    }
+
+    #[test]
+    fn split_amount_denom_parses_amount_and_denom() {
+        assert_eq!(
+            split_amount_denom("1000uatom"),
+            Some((1000, "uatom".to_string()))
+        );
+    }
+
+    #[test]
+    fn split_amount_denom_rejects_missing_denom() {
+        assert_eq!(split_amount_denom("1000"), None);
+    }
+}
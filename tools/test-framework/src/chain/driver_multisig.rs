@@ -0,0 +1,190 @@
+/*!
+   [`ChainDriver`] methods for signing and broadcasting transactions on
+   behalf of a k-of-n multisig account.
+*/
+
+use cosmos_sdk_proto::cosmos::crypto::multisig::v1beta1::{CompactBitArray, MultiSignature};
+use cosmos_sdk_proto::cosmos::tx::v1beta1::TxRaw;
+use ibc_proto::google::protobuf::Any;
+use ibc_relayer::chain::cosmos::tx::{sign_tx_partial, PartialSignature};
+use ibc_relayer::util::block_on;
+use prost::Message;
+
+use crate::chain::driver::ChainDriver;
+use crate::error::Error;
+use crate::types::multisig::{MultisigConfig, MultisigPublicKey};
+use crate::types::tx_response::TxResponse;
+use crate::types::wallet::Wallet;
+
+impl ChainDriver {
+    /**
+       Collect a partial signature from each of `signers`, combine them
+       into `multisig`'s combined signature together with the matching
+       signer bitmap, assemble the final signed transaction, and
+       broadcast it.
+    */
+    pub fn send_tx_multisig(
+        &self,
+        multisig: &MultisigConfig,
+        signers: &[&Wallet],
+        messages: Vec<Any>,
+    ) -> Result<TxResponse, Error> {
+        multisig.check_satisfied_by(signers.len())?;
+
+        let partial_signatures: Vec<PartialSignature> = signers
+            .iter()
+            .map(|signer| block_on(sign_tx_partial(&self.tx_config, signer, &messages)).map_err(Error::send_tx_failed))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Every signer signs over the same unsigned tx, so the body and
+        // auth info bytes are identical across `partial_signatures`.
+        let unsigned = partial_signatures
+            .first()
+            .ok_or_else(|| Error::insufficient_multisig_signers(0, multisig.threshold))?;
+
+        let body_bytes = unsigned.body_bytes.clone();
+        let auth_info_bytes = unsigned.auth_info_bytes.clone();
+
+        let signed_by_member = signers
+            .iter()
+            .zip(partial_signatures.iter())
+            .map(|(signer, partial)| Ok((member_index(multisig, signer)?, partial.signature.clone())))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let combined = combine_signatures(multisig, body_bytes, auth_info_bytes, signed_by_member)?;
+
+        let response = block_on(self.broadcast_signed_tx(combined)).map_err(Error::send_tx_failed)?;
+
+        crate::chain::driver_tx::decode_tx_commit_response(response)
+    }
+}
+
+/**
+   Assembles the final signed transaction: the unsigned `body_bytes`/
+   `auth_info_bytes`, together with the combined multisig signature (the
+   signer bitmap plus each signer's signature, in ascending member-index
+   order), encoded as a `TxRaw`.
+*/
+fn combine_signatures(
+    multisig: &MultisigConfig,
+    body_bytes: Vec<u8>,
+    auth_info_bytes: Vec<u8>,
+    signed_by_member: Vec<(usize, Vec<u8>)>,
+) -> Result<Vec<u8>, Error> {
+    let (bitmap, multi_signature) = build_multisig_signature(multisig, signed_by_member);
+
+    let mut multisig_signature = Vec::new();
+    bitmap
+        .encode(&mut multisig_signature)
+        .map_err(|_| Error::multisig_encode_failed())?;
+    multi_signature
+        .encode(&mut multisig_signature)
+        .map_err(|_| Error::multisig_encode_failed())?;
+
+    let tx_raw = TxRaw {
+        body_bytes,
+        auth_info_bytes,
+        signatures: vec![multisig_signature],
+    };
+
+    let mut buf = Vec::new();
+    tx_raw.encode(&mut buf).map_err(|_| Error::multisig_encode_failed())?;
+
+    Ok(buf)
+}
+
+/**
+   Builds the signer bitmap and the combined signature list, sorting
+   `signed_by_member` into ascending member-index order first: the
+   verifier matches `MultiSignature.signatures[i]` against the `i`-th set
+   bit of the bitmap, so the signatures must be in the same order as the
+   members they belong to, not the order they were collected in.
+*/
+fn build_multisig_signature(
+    multisig: &MultisigConfig,
+    mut signed_by_member: Vec<(usize, Vec<u8>)>,
+) -> (CompactBitArray, MultiSignature) {
+    signed_by_member.sort_by_key(|(index, _)| *index);
+
+    let mut elems = vec![0u8; multisig.members.len().div_ceil(8)];
+    let mut signatures = Vec::with_capacity(signed_by_member.len());
+
+    for (index, signature) in signed_by_member {
+        elems[index / 8] |= 0b1000_0000 >> (index % 8);
+        signatures.push(signature);
+    }
+
+    let bitmap = CompactBitArray {
+        extra_bits_stored: (multisig.members.len() % 8) as u32,
+        elems,
+    };
+
+    (bitmap, MultiSignature { signatures })
+}
+
+fn member_index(multisig: &MultisigConfig, signer: &Wallet) -> Result<usize, Error> {
+    multisig
+        .members
+        .iter()
+        .position(|member| member_matches(member, signer))
+        .ok_or_else(Error::multisig_signer_not_a_member)
+}
+
+fn member_matches(member: &MultisigPublicKey, signer: &Wallet) -> bool {
+    member.0 == signer.public_key_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(member_count: u8) -> MultisigConfig {
+        let members = (0..member_count).map(|i| MultisigPublicKey(vec![i])).collect();
+        MultisigConfig::new(members, 1).unwrap()
+    }
+
+    #[test]
+    fn build_multisig_signature_sorts_out_of_order_signers_by_member_index() {
+        let multisig = config(3);
+
+        // Collected in reverse member order.
+        let signed_by_member = vec![(2, vec![0xCC]), (0, vec![0xAA]), (1, vec![0xBB])];
+
+        let (_, multi_signature) = build_multisig_signature(&multisig, signed_by_member);
+
+        assert_eq!(
+            multi_signature.signatures,
+            vec![vec![0xAA], vec![0xBB], vec![0xCC]]
+        );
+    }
+
+    #[test]
+    fn build_multisig_signature_sets_only_the_signing_members_bits() {
+        let multisig = config(3);
+
+        let (bitmap, _) = build_multisig_signature(&multisig, vec![(0, vec![0xAA]), (2, vec![0xCC])]);
+
+        assert_eq!(bitmap.elems, vec![0b1010_0000]);
+    }
+
+    #[test]
+    fn combine_signatures_preserves_unsigned_tx_bytes() {
+        let multisig = config(2);
+        let body_bytes = vec![0x01, 0x02];
+        let auth_info_bytes = vec![0x03, 0x04];
+
+        let combined = combine_signatures(
+            &multisig,
+            body_bytes.clone(),
+            auth_info_bytes.clone(),
+            vec![(0, vec![0xAA]), (1, vec![0xBB])],
+        )
+        .unwrap();
+
+        let tx_raw = TxRaw::decode(combined.as_slice()).unwrap();
+
+        assert_eq!(tx_raw.body_bytes, body_bytes);
+        assert_eq!(tx_raw.auth_info_bytes, auth_info_bytes);
+        assert_eq!(tx_raw.signatures.len(), 1);
+    }
+}
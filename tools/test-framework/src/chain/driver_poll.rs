@@ -0,0 +1,49 @@
+/*!
+   [`ChainDriver`] method for polling a wallet's balance with an explicit
+   poll interval and timeout.
+*/
+
+use core::time::Duration;
+use std::thread::sleep;
+use std::time::Instant;
+
+use crate::chain::driver::ChainDriver;
+use crate::error::Error;
+use crate::ibc::denom::Denom;
+use crate::types::wallet::WalletAddress;
+
+impl ChainDriver {
+    /**
+       Poll `user`'s balance in `denom` every `poll_interval` until it
+       reaches `target_amount`, failing with [`Error::eventual_amount_timeout`]
+       if `timeout` elapses first.
+    */
+    pub fn assert_eventual_wallet_amount_within(
+        &self,
+        user: &WalletAddress,
+        target_amount: u64,
+        denom: &Denom,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let start = Instant::now();
+
+        loop {
+            let balance = self.query_balance(user, denom)?;
+
+            if balance == target_amount {
+                return Ok(());
+            }
+
+            if start.elapsed() >= timeout {
+                return Err(Error::eventual_amount_timeout(
+                    user.0.clone(),
+                    target_amount,
+                    balance,
+                ));
+            }
+
+            sleep(poll_interval);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+/*!
+   [`ChainDriver`] methods for signing and broadcasting transactions and
+   decoding the resulting delivery response.
+
+   This is where [`TaggedChainDriverExt::send_tx`](crate::chain::tagged::TaggedChainDriverExt::send_tx)'s
+   decoding actually happens: [`ChainDriver::send_tx`] signs and
+   broadcasts `messages`, then decodes the tx hash, gas used/wanted,
+   result code, and ABCI events off of the raw delivery response.
+*/
+
+use ibc_proto::google::protobuf::Any;
+use ibc_relayer::chain::cosmos::tx::simple_send_tx;
+use ibc_relayer::util::block_on;
+use tendermint::abci::Event as AbciEvent;
+use tendermint_rpc::endpoint::broadcast::tx_commit::Response as TxCommitResponse;
+
+use crate::chain::driver::ChainDriver;
+use crate::error::Error;
+use crate::types::tx_response::{EventAttribute, TxEvent, TxResponse};
+use crate::types::wallet::Wallet;
+
+impl ChainDriver {
+    /**
+       Sign and broadcast `messages` with `wallet`, returning the decoded
+       [`TxResponse`].
+    */
+    pub fn send_tx(&self, wallet: &Wallet, messages: Vec<Any>) -> Result<TxResponse, Error> {
+        let response = block_on(simple_send_tx(&self.tx_config, wallet, messages))
+            .map_err(Error::send_tx_failed)?;
+
+        decode_tx_commit_response(response)
+    }
+}
+
+/**
+   Decodes a tx commit response into a [`TxResponse`], copying each
+   event's key/value attributes as decoded by `tendermint-rs`.
+*/
+pub(crate) fn decode_tx_commit_response(response: TxCommitResponse) -> Result<TxResponse, Error> {
+    let events = response.deliver_tx.events.iter().map(decode_event).collect();
+
+    Ok(TxResponse {
+        tx_hash: response.hash.to_string(),
+        code: response.deliver_tx.code.value(),
+        gas_used: response.deliver_tx.gas_used.value(),
+        gas_wanted: response.deliver_tx.gas_wanted.value(),
+        events,
+    })
+}
+
+/**
+   `tendermint-rs` already decodes each event attribute's key/value
+   according to the ABCI protocol version in use, so no further decoding
+   is needed here.
+*/
+fn decode_event(event: &AbciEvent) -> TxEvent {
+    let attributes = event
+        .attributes
+        .iter()
+        .map(|attribute| EventAttribute {
+            key: attribute.key.to_string(),
+            value: attribute.value.to_string(),
+        })
+        .collect();
+
+    TxEvent {
+        kind: event.kind.clone(),
+        attributes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint::abci::Event as AbciEvent;
+    use tendermint::abci::EventAttribute as AbciEventAttribute;
+
+    use super::decode_event;
+    use crate::types::tx_response::{EventAttribute, TxEvent, TxResponse};
+
+    fn sample_response() -> TxResponse {
+        TxResponse {
+            tx_hash: "ABCD".to_string(),
+            code: 0,
+            gas_used: 100,
+            gas_wanted: 200,
+            events: vec![
+                TxEvent {
+                    kind: "send_packet".to_string(),
+                    attributes: vec![
+                        EventAttribute {
+                            key: "packet_sequence".to_string(),
+                            value: "1".to_string(),
+                        },
+                        EventAttribute {
+                            key: "packet_src_channel".to_string(),
+                            value: "channel-0".to_string(),
+                        },
+                    ],
+                },
+                TxEvent {
+                    kind: "write_acknowledgement".to_string(),
+                    attributes: vec![],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn events_of_type_filters_by_kind() {
+        let response = sample_response();
+        let send_packets: Vec<_> = response.events_of_type("send_packet").collect();
+        assert_eq!(send_packets.len(), 1);
+    }
+
+    #[test]
+    fn events_of_type_returns_nothing_for_unknown_kind() {
+        let response = sample_response();
+        assert_eq!(response.events_of_type("recv_packet").count(), 0);
+    }
+
+    #[test]
+    fn attribute_finds_matching_key() {
+        let response = sample_response();
+        let event = &response.events[0];
+        assert_eq!(event.attribute("packet_sequence"), Some("1"));
+        assert_eq!(event.attribute("packet_src_channel"), Some("channel-0"));
+    }
+
+    #[test]
+    fn attribute_returns_none_for_missing_key() {
+        let response = sample_response();
+        let event = &response.events[0];
+        assert_eq!(event.attribute("packet_dst_channel"), None);
+    }
+
+    #[test]
+    fn is_success_reflects_code() {
+        let response = sample_response();
+        assert!(response.is_success());
+    }
+
+    #[test]
+    fn decode_event_passes_through_attributes_unchanged() {
+        let event = AbciEvent {
+            kind: "transfer".to_string(),
+            attributes: vec![AbciEventAttribute {
+                key: "recipient".to_string().into(),
+                value: "cosmos1abc".to_string().into(),
+                index: false,
+            }],
+        };
+
+        let decoded = decode_event(&event);
+
+        assert_eq!(decoded.kind, "transfer");
+        assert_eq!(decoded.attribute("recipient"), Some("cosmos1abc"));
+    }
+}
@@ -0,0 +1,101 @@
+/*!
+   A registry of known chains, pairing each chain-id prefix with its
+   average block time, used to derive a poll interval and default
+   timeout for [`TaggedChainDriverExt::assert_eventual_wallet_amount`](crate::chain::tagged::TaggedChainDriverExt::assert_eventual_wallet_amount).
+*/
+
+use core::time::Duration;
+
+/**
+   Metadata about a chain's block production that is relevant to test
+   polling: how long on average a block takes, and the poll interval and
+   default timeout derived from it.
+*/
+#[derive(Debug, Clone, Copy)]
+pub struct ChainMetadata {
+    pub average_block_time: Duration,
+    pub poll_interval: Duration,
+    pub default_timeout: Duration,
+}
+
+impl ChainMetadata {
+    fn from_average_block_time(average_block_time: Duration) -> Self {
+        Self {
+            average_block_time,
+            poll_interval: average_block_time / 2,
+            default_timeout: average_block_time * 20,
+        }
+    }
+}
+
+/**
+   Known chain-id prefixes mapped to their [`ChainMetadata`].
+
+   `lookup` matches a chain id against the known prefixes (e.g.
+   `cosmoshub-4` matches `cosmoshub`, `osmosis-1` matches `osmosis`) and
+   falls back to [`ChainRegistry::default_metadata`] for unrecognized
+   chains. `gaia`, `ibc-alpha` and `ibc-beta` chain ids, as used by
+   local test chains, are listed with a one second block time.
+*/
+pub struct ChainRegistry;
+
+impl ChainRegistry {
+    const KNOWN_CHAINS: &'static [(&'static str, u64)] = &[
+        ("cosmoshub", 7_000),
+        ("osmosis", 5_000),
+        ("gaia", 1_000),
+        ("ibc-alpha", 1_000),
+        ("ibc-beta", 1_000),
+    ];
+
+    /**
+       The metadata used for chains that don't match any known prefix:
+       a conservative one second average block time, as used by the
+       local `gaiad`-based test chains.
+    */
+    pub fn default_metadata() -> ChainMetadata {
+        ChainMetadata::from_average_block_time(Duration::from_secs(1))
+    }
+
+    /**
+       Look up the [`ChainMetadata`] for a chain id, matching by known
+       prefix and falling back to [`ChainRegistry::default_metadata`].
+    */
+    pub fn lookup(chain_id: &str) -> ChainMetadata {
+        Self::KNOWN_CHAINS
+            .iter()
+            .find(|(prefix, _)| chain_id.starts_with(prefix))
+            .map(|(_, millis)| ChainMetadata::from_average_block_time(Duration::from_millis(*millis)))
+            .unwrap_or_else(Self::default_metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_matches_known_prefix() {
+        let metadata = ChainRegistry::lookup("cosmoshub-4");
+        assert_eq!(metadata.average_block_time, Duration::from_millis(7_000));
+    }
+
+    #[test]
+    fn lookup_matches_another_known_prefix() {
+        let metadata = ChainRegistry::lookup("osmosis-1");
+        assert_eq!(metadata.average_block_time, Duration::from_millis(5_000));
+    }
+
+    #[test]
+    fn lookup_falls_back_to_default_for_unknown_chain() {
+        let metadata = ChainRegistry::lookup("some-unknown-chain-1");
+        assert_eq!(metadata.average_block_time, ChainRegistry::default_metadata().average_block_time);
+    }
+
+    #[test]
+    fn poll_interval_and_timeout_are_derived_from_block_time() {
+        let metadata = ChainRegistry::lookup("gaia-0");
+        assert_eq!(metadata.poll_interval, metadata.average_block_time / 2);
+        assert_eq!(metadata.default_timeout, metadata.average_block_time * 20);
+    }
+}
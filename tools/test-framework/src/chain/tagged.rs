@@ -2,17 +2,30 @@
    Methods for tagged version of the chain driver.
 */
 
+use core::time::Duration;
+
+use cosmrs::AccountId;
+use ibc::core::ics23_commitment::merkle::MerkleProof;
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use ics23::commitment_proof::Proof as Ics23Proof;
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer::chain::cosmos::types::config::TxConfig;
+use ibc_relayer::util::block_on;
+use prost::Message;
 use serde_json as json;
+use tendermint_rpc::{Client, HttpClient};
 
 use crate::chain::cli::query::query_recipient_transactions;
 use crate::chain::driver::ChainDriver;
+use crate::chain::registry::ChainRegistry;
 use crate::error::Error;
 use crate::ibc::denom::Denom;
 use crate::types::id::TaggedChainIdRef;
+use crate::types::multisig::TaggedMultisigConfig;
 use crate::types::tagged::*;
+use crate::types::tx_response::TxResponse;
 use crate::types::wallet::{Wallet, WalletAddress};
+use crate::types::wallet_history::{TaggedWalletHistoryPage, WalletHistoryCursor};
 
 /**
    A [`ChainDriver`] may be tagged with a `Chain` tag in the form
@@ -29,8 +42,31 @@ pub trait TaggedChainDriverExt<Chain> {
 
     fn tx_config(&self) -> MonoTagged<Chain, &TxConfig>;
 
-    fn send_tx(&self, wallet: &MonoTagged<Chain, &Wallet>, messages: Vec<Any>)
-        -> Result<(), Error>;
+    /**
+       Send a transaction signed by `wallet` and return the decoded
+       [`TxResponse`], including the tx hash, gas used/wanted, the result
+       code, and the fully decoded ABCI events.
+
+       Tests that submit IBC messages can pull `send_packet`/
+       `recv_packet`/`write_acknowledgement` events straight out of the
+       returned response instead of re-querying the chain afterwards.
+    */
+    fn send_tx(
+        &self,
+        wallet: &MonoTagged<Chain, &Wallet>,
+        messages: Vec<Any>,
+    ) -> Result<MonoTagged<Chain, TxResponse>, Error>;
+
+    /**
+       Same as [`TaggedChainDriverExt::send_tx`], but discards the
+       decoded response for callers that only care whether the
+       transaction was submitted without error.
+    */
+    fn send_tx_silent(
+        &self,
+        wallet: &MonoTagged<Chain, &Wallet>,
+        messages: Vec<Any>,
+    ) -> Result<(), Error>;
 
     /**
        Tagged version of [`ChainDriver::query_balance`].
@@ -48,7 +84,9 @@ pub trait TaggedChainDriverExt<Chain> {
        Tagged version of [`ChainDriver::assert_eventual_wallet_amount`].
 
        Assert that a wallet belongs to `Chain` would reach the target
-       amount in the denomination that belongs to `Chain`.
+       amount in the denomination that belongs to `Chain`. The poll
+       interval and timeout are looked up from [`ChainRegistry`] based on
+       `Chain`'s chain id.
     */
     fn assert_eventual_wallet_amount(
         &self,
@@ -57,6 +95,19 @@ pub trait TaggedChainDriverExt<Chain> {
         denom: &MonoTagged<Chain, &Denom>,
     ) -> Result<(), Error>;
 
+    /**
+       Same as [`TaggedChainDriverExt::assert_eventual_wallet_amount`],
+       but with an explicit timeout instead of the one derived from the
+       [`ChainRegistry`].
+    */
+    fn assert_eventual_wallet_amount_within(
+        &self,
+        user: &MonoTagged<Chain, &WalletAddress>,
+        target_amount: u64,
+        denom: &MonoTagged<Chain, &Denom>,
+        timeout: Duration,
+    ) -> Result<(), Error>;
+
     /**
         Taggged version of [`query_recipient_transactions`].
 
@@ -67,6 +118,68 @@ pub trait TaggedChainDriverExt<Chain> {
         &self,
         recipient_address: &MonoTagged<Chain, &WalletAddress>,
     ) -> Result<json::Value, Error>;
+
+    /**
+       Tagged version of the bank balance query, together with the ABCI
+       Merkle proof for the underlying balance key, verified against the
+       chain's app hash at the queried height.
+    */
+    fn query_balance_with_proof(
+        &self,
+        wallet_id: &MonoTagged<Chain, &WalletAddress>,
+        denom: &MonoTagged<Chain, &Denom>,
+    ) -> Result<MonoTagged<Chain, (u64, MerkleProof)>, Error>;
+
+    /**
+       Fail with [`Error::tx_failed`] if `response` carries a non-zero
+       result code, otherwise return successfully.
+    */
+    fn assert_tx_success(&self, response: &MonoTagged<Chain, TxResponse>) -> Result<(), Error>;
+
+    /**
+       Send a transaction signed by a k-of-n multisig account.
+
+       Collects a partial signature from each of `signers` (which must be
+       numerous enough to meet the `multisig`'s threshold), assembles the
+       combined signature together with the signer bitmap, and
+       broadcasts the transaction.
+    */
+    fn send_tx_multisig(
+        &self,
+        multisig: &TaggedMultisigConfig<Chain>,
+        signers: &[MonoTagged<Chain, &Wallet>],
+        messages: Vec<Any>,
+    ) -> Result<MonoTagged<Chain, TxResponse>, Error>;
+
+    /**
+       Tagged version of [`ChainDriver::query_wallet_history`]. Query for
+       a page of `wallet_address`'s transfer history on `Chain`, covering
+       both sent and received transfers, decoded into tagged transfers.
+
+       This extends [`TaggedChainDriverExt::query_recipient_transactions`],
+       which only returns raw [`json::Value`]. Pass the
+       [`TaggedWalletHistoryPage::next_cursor`] from a previous call back
+       in as `cursor` to continue past `page_size` entries.
+    */
+    fn query_wallet_history(
+        &self,
+        wallet_address: &MonoTagged<Chain, &WalletAddress>,
+        page_size: u32,
+        cursor: Option<WalletHistoryCursor>,
+    ) -> Result<TaggedWalletHistoryPage<Chain>, Error>;
+
+    /**
+       Assert that `to` has received a transfer of `amount` in `denom`
+       from `from`, paging through [`TaggedChainDriverExt::query_wallet_history`]
+       as needed.
+    */
+    fn assert_wallet_received(
+        &self,
+        to: &MonoTagged<Chain, &WalletAddress>,
+        from: &MonoTagged<Chain, &WalletAddress>,
+        amount: u64,
+        denom: &MonoTagged<Chain, &Denom>,
+    ) -> Result<(), Error>;
 }
 
 impl<'a, Chain: Send> TaggedChainDriverExt<Chain> for MonoTagged<Chain, &'a ChainDriver> {
@@ -82,8 +195,20 @@ impl<'a, Chain: Send> TaggedChainDriverExt<Chain> for MonoTagged<Chain, &'a Chai
         &self,
         wallet: &MonoTagged<Chain, &Wallet>,
         messages: Vec<Any>,
+    ) -> Result<MonoTagged<Chain, TxResponse>, Error> {
+        let response = self.value().send_tx(wallet.value(), messages)?;
+
+        Ok(MonoTagged::new(response))
+    }
+
+    fn send_tx_silent(
+        &self,
+        wallet: &MonoTagged<Chain, &Wallet>,
+        messages: Vec<Any>,
     ) -> Result<(), Error> {
-        self.value().send_tx(wallet.value(), messages)
+        self.send_tx(wallet, messages)?;
+
+        Ok(())
     }
 
     fn query_balance(
@@ -100,8 +225,33 @@ impl<'a, Chain: Send> TaggedChainDriverExt<Chain> for MonoTagged<Chain, &'a Chai
         target_amount: u64,
         denom: &MonoTagged<Chain, &Denom>,
     ) -> Result<(), Error> {
-        self.value()
-            .assert_eventual_wallet_amount(user.value(), target_amount, denom.value())
+        let metadata = ChainRegistry::lookup(self.value().chain_id.as_str());
+
+        self.value().assert_eventual_wallet_amount_within(
+            user.value(),
+            target_amount,
+            denom.value(),
+            metadata.poll_interval,
+            metadata.default_timeout,
+        )
+    }
+
+    fn assert_eventual_wallet_amount_within(
+        &self,
+        user: &MonoTagged<Chain, &WalletAddress>,
+        target_amount: u64,
+        denom: &MonoTagged<Chain, &Denom>,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let metadata = ChainRegistry::lookup(self.value().chain_id.as_str());
+
+        self.value().assert_eventual_wallet_amount_within(
+            user.value(),
+            target_amount,
+            denom.value(),
+            metadata.poll_interval,
+            timeout,
+        )
     }
 
     fn query_recipient_transactions(
@@ -116,4 +266,265 @@ impl<'a, Chain: Send> TaggedChainDriverExt<Chain> for MonoTagged<Chain, &'a Chai
             &recipient_address.value().0,
         )
     }
+
+    fn query_balance_with_proof(
+        &self,
+        wallet_id: &MonoTagged<Chain, &WalletAddress>,
+        denom: &MonoTagged<Chain, &Denom>,
+    ) -> Result<MonoTagged<Chain, (u64, MerkleProof)>, Error> {
+        let driver = self.value();
+
+        let (balance, proof) =
+            query_balance_with_proof(&driver.rpc_listen_address(), &wallet_id.value().0, &denom.value().0)?;
+
+        Ok(MonoTagged::new((balance, proof)))
+    }
+
+    fn assert_tx_success(&self, response: &MonoTagged<Chain, TxResponse>) -> Result<(), Error> {
+        let response = response.value();
+
+        if response.is_success() {
+            Ok(())
+        } else {
+            Err(Error::tx_failed(response.code, response.tx_hash.clone()))
+        }
+    }
+
+    fn send_tx_multisig(
+        &self,
+        multisig: &TaggedMultisigConfig<Chain>,
+        signers: &[MonoTagged<Chain, &Wallet>],
+        messages: Vec<Any>,
+    ) -> Result<MonoTagged<Chain, TxResponse>, Error> {
+        multisig.value().check_satisfied_by(signers.len())?;
+
+        let signer_wallets: Vec<&Wallet> = signers.iter().map(|signer| *signer.value()).collect();
+
+        let response = self
+            .value()
+            .send_tx_multisig(multisig.value(), &signer_wallets, messages)?;
+
+        Ok(MonoTagged::new(response))
+    }
+
+    fn query_wallet_history(
+        &self,
+        wallet_address: &MonoTagged<Chain, &WalletAddress>,
+        page_size: u32,
+        cursor: Option<WalletHistoryCursor>,
+    ) -> Result<TaggedWalletHistoryPage<Chain>, Error> {
+        let page = self
+            .value()
+            .query_wallet_history(wallet_address.value(), page_size, cursor)?;
+
+        Ok(page.tagged())
+    }
+
+    fn assert_wallet_received(
+        &self,
+        to: &MonoTagged<Chain, &WalletAddress>,
+        from: &MonoTagged<Chain, &WalletAddress>,
+        amount: u64,
+        denom: &MonoTagged<Chain, &Denom>,
+    ) -> Result<(), Error> {
+        let mut cursor = None;
+
+        loop {
+            let page = self.query_wallet_history(to, 50, cursor)?;
+
+            let found = page.transfers.iter().any(|transfer| {
+                transfer.counterparty.0 == from.value().0
+                    && transfer.amount == amount
+                    && transfer.denom.value() == denom.value()
+            });
+
+            if found {
+                return Ok(());
+            }
+
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => {
+                    return Err(Error::wallet_transfer_not_found(
+                        to.value().0.clone(),
+                        from.value().0.clone(),
+                        amount,
+                    ))
+                }
+            }
+        }
+    }
+}
+
+/**
+   The Cosmos SDK bank module's `BalancesPrefix` store key prefix byte
+   (`x/bank/types/key.go`).
+*/
+const BALANCES_PREFIX: u8 = 0x02;
+
+/**
+   Builds the bank module's KVStore key for a balance: the balances
+   prefix byte, followed by the length-prefixed raw account address,
+   followed by the denom.
+*/
+fn balance_store_key(address: &[u8], denom: &str) -> Vec<u8> {
+    let mut key = Vec::with_capacity(2 + address.len() + denom.len());
+    key.push(BALANCES_PREFIX);
+    key.push(address.len() as u8);
+    key.extend_from_slice(address);
+    key.extend_from_slice(denom.as_bytes());
+    key
+}
+
+/**
+   Queries the bank balance store key for `address`/`denom` with
+   `prove = true`, decodes the returned value as a `Coin`, then verifies
+   the returned proof commits to the app hash at the queried height.
+
+   `data` is a raw binary key (a length-prefixed account address is not
+   valid UTF-8 in general), so verification is done directly against the
+   `ics23` crate's proof primitives on `&[u8]` rather than through
+   [`ibc`]'s `MerklePath`, whose `key_path: Vec<String>` can only
+   round-trip UTF-8-safe keys.
+
+   Returns the decoded balance together with the verified [`MerkleProof`]
+   on success, or an [`Error`] if the value fails to decode as a `Coin`
+   or the proof does not commit to the block's app hash.
+*/
+fn query_balance_with_proof(
+    rpc_address: &tendermint_rpc::Url,
+    address: &str,
+    denom: &str,
+) -> Result<(u64, MerkleProof), Error> {
+    let client = HttpClient::new(rpc_address.as_str()).map_err(Error::tendermint_rpc)?;
+
+    let account_id: AccountId = address
+        .parse()
+        .map_err(|_| Error::invalid_wallet_address(address.to_string()))?;
+
+    let data = balance_store_key(&account_id.to_bytes(), denom);
+    let path = "store/bank/key".to_string();
+
+    let response = block_on(client.abci_query(
+        Some(path.parse().map_err(|_| Error::query_failed(path.clone()))?),
+        data.clone(),
+        None,
+        true,
+    ))
+    .map_err(Error::tendermint_rpc)?;
+
+    let coin = Coin::decode(response.value.as_slice())
+        .map_err(|_| Error::coin_decode_failed(address.to_string(), denom.to_string()))?;
+
+    let balance: u64 = coin
+        .amount
+        .parse()
+        .map_err(|_| Error::coin_decode_failed(address.to_string(), denom.to_string()))?;
+
+    let tm_proof = response
+        .proof
+        .ok_or_else(|| Error::merkle_proof_verification_failed(address.to_string()))?;
+
+    let merkle_proof = MerkleProof::from(tm_proof);
+
+    let block = block_on(client.block(response.height)).map_err(Error::tendermint_rpc)?;
+    let app_hash = block.block.header.app_hash;
+
+    verify_raw_membership(&merkle_proof, app_hash.as_bytes(), &data, &response.value)
+        .map_err(|_| Error::merkle_proof_verification_failed(address.to_string()))?;
+
+    Ok((balance, merkle_proof))
+}
+
+/**
+   Verifies a two-layer Cosmos SDK store proof directly against raw byte
+   keys: `key`/`value` are checked to exist under the IAVL store's root
+   (`proofs[0]`), and that store root is in turn checked to be committed
+   under the `"bank"` store name in the multistore root `app_hash`
+   (`proofs[1]`).
+
+   This works on `&[u8]` throughout, unlike `MerkleProof::verify_membership`,
+   which requires the key to round-trip through a UTF-8 `String`.
+*/
+fn verify_raw_membership(merkle_proof: &MerkleProof, app_hash: &[u8], key: &[u8], value: &[u8]) -> Result<(), Error> {
+    let store_proof = merkle_proof
+        .proofs
+        .first()
+        .ok_or_else(|| Error::merkle_proof_verification_failed("missing store-level proof".to_string()))?;
+
+    let multistore_proof = merkle_proof
+        .proofs
+        .get(1)
+        .ok_or_else(|| Error::merkle_proof_verification_failed("missing multistore-level proof".to_string()))?;
+
+    let store_existence_proof = existence_proof(store_proof)?;
+
+    let store_root = ics23::calculate_existence_root(store_existence_proof)
+        .map_err(|_| Error::merkle_proof_verification_failed("could not recompute store root".to_string()))?;
+
+    if !ics23::verify_membership(store_proof, &ics23::iavl_spec(), &store_root, key, value) {
+        return Err(Error::merkle_proof_verification_failed(
+            "store-level proof does not commit to key/value".to_string(),
+        ));
+    }
+
+    if !ics23::verify_membership(
+        multistore_proof,
+        &ics23::tendermint_spec(),
+        app_hash,
+        b"bank",
+        &store_root,
+    ) {
+        return Err(Error::merkle_proof_verification_failed(
+            "multistore-level proof does not commit to app hash".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn existence_proof(proof: &ics23::CommitmentProof) -> Result<&ics23::ExistenceProof, Error> {
+    match &proof.proof {
+        Some(Ics23Proof::Exist(existence_proof)) => Ok(existence_proof),
+        _ => Err(Error::merkle_proof_verification_failed(
+            "expected an existence proof".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn balance_store_key_starts_with_balances_prefix() {
+        let key = balance_store_key(&[0xAA, 0xBB], "uatom");
+        assert_eq!(key[0], BALANCES_PREFIX);
+    }
+
+    #[test]
+    fn balance_store_key_length_prefixes_the_address() {
+        let address = [0x01, 0x02, 0x03];
+        let key = balance_store_key(&address, "uatom");
+
+        assert_eq!(key[1], address.len() as u8);
+        assert_eq!(&key[2..2 + address.len()], &address);
+    }
+
+    #[test]
+    fn balance_store_key_appends_raw_denom_bytes() {
+        let key = balance_store_key(&[0x01], "uatom");
+        assert_eq!(&key[3..], b"uatom");
+    }
+
+    #[test]
+    fn balance_store_key_preserves_non_utf8_address_bytes() {
+        // An address byte in the 0x80..=0xFF range is not valid UTF-8 on
+        // its own, so this key must be handled as raw bytes rather than
+        // round-tripped through a `String`.
+        let address = [0xFF, 0x00, 0xFE];
+        let key = balance_store_key(&address, "uatom");
+
+        assert_eq!(&key[2..2 + address.len()], &address);
+    }
 }
@@ -0,0 +1,98 @@
+/*!
+   Type definitions for tagged multisig accounts.
+*/
+
+use crate::error::Error;
+use crate::types::tagged::MonoTagged;
+
+/**
+   A member public key of a [`MultisigConfig`], in the chain's native
+   encoding (e.g. a compressed secp256k1 public key).
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisigPublicKey(pub Vec<u8>);
+
+/**
+   A k-of-n multisig account: the set of member public keys plus the
+   signing threshold.
+
+   Tagging this with a `Chain` (as [`TaggedMultisigConfig`]) ensures a
+   multisig account can only be used to sign transactions for the chain
+   it was configured for.
+*/
+#[derive(Debug, Clone)]
+pub struct MultisigConfig {
+    pub members: Vec<MultisigPublicKey>,
+    pub threshold: u32,
+}
+
+impl MultisigConfig {
+    /**
+       Construct a [`MultisigConfig`], failing with
+       [`Error::invalid_multisig_threshold`] unless the threshold is
+       between 1 and the number of members, inclusive.
+    */
+    pub fn new(members: Vec<MultisigPublicKey>, threshold: u32) -> Result<Self, Error> {
+        if threshold == 0 || threshold as usize > members.len() {
+            return Err(Error::invalid_multisig_threshold(threshold, members.len()));
+        }
+
+        Ok(Self { members, threshold })
+    }
+
+    /**
+       Check whether `signer_count` signers is enough to satisfy this
+       multisig's threshold, failing with
+       [`Error::insufficient_multisig_signers`] otherwise.
+    */
+    pub fn check_satisfied_by(&self, signer_count: usize) -> Result<(), Error> {
+        if signer_count < self.threshold as usize {
+            return Err(Error::insufficient_multisig_signers(
+                signer_count,
+                self.threshold,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/**
+   A [`MultisigConfig`] tagged with the `Chain` it belongs to.
+*/
+pub type TaggedMultisigConfig<Chain> = MonoTagged<Chain, MultisigConfig>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> MultisigPublicKey {
+        MultisigPublicKey(vec![byte])
+    }
+
+    #[test]
+    fn new_accepts_valid_threshold() {
+        let config = MultisigConfig::new(vec![key(1), key(2), key(3)], 2);
+        assert!(config.is_ok());
+    }
+
+    #[test]
+    fn new_rejects_zero_threshold() {
+        let config = MultisigConfig::new(vec![key(1), key(2)], 0);
+        assert!(config.is_err());
+    }
+
+    #[test]
+    fn new_rejects_threshold_above_member_count() {
+        let config = MultisigConfig::new(vec![key(1), key(2)], 3);
+        assert!(config.is_err());
+    }
+
+    #[test]
+    fn check_satisfied_by_requires_at_least_threshold_signers() {
+        let config = MultisigConfig::new(vec![key(1), key(2), key(3)], 2).unwrap();
+        assert!(config.check_satisfied_by(1).is_err());
+        assert!(config.check_satisfied_by(2).is_ok());
+        assert!(config.check_satisfied_by(3).is_ok());
+    }
+}
@@ -0,0 +1,68 @@
+/*!
+   Type definition for a decoded transaction response returned by
+   [`TaggedChainDriverExt::send_tx`](crate::chain::tagged::TaggedChainDriverExt::send_tx).
+*/
+
+/**
+   A single attribute of a decoded ABCI event, with the key and value
+   already base64-decoded into plain strings.
+*/
+#[derive(Debug, Clone)]
+pub struct EventAttribute {
+    pub key: String,
+    pub value: String,
+}
+
+/**
+   A decoded ABCI event, as found in the `events` field of a transaction
+   delivery response.
+*/
+#[derive(Debug, Clone)]
+pub struct TxEvent {
+    pub kind: String,
+    pub attributes: Vec<EventAttribute>,
+}
+
+impl TxEvent {
+    /**
+       Look up the first attribute with the given key, if any.
+    */
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.as_str())
+    }
+}
+
+/**
+   The decoded result of broadcasting a transaction, as returned by
+   [`send_tx`](crate::chain::tagged::TaggedChainDriverExt::send_tx).
+
+   Carries enough information for tests to assert on delivery (the
+   `code`) and to pull IBC packet data (sequence numbers, channel/port
+   ids, acknowledgements) straight out of the emitted events, without
+   having to re-query the chain afterwards.
+*/
+#[derive(Debug, Clone)]
+pub struct TxResponse {
+    pub tx_hash: String,
+    pub code: u32,
+    pub gas_used: u64,
+    pub gas_wanted: u64,
+    pub events: Vec<TxEvent>,
+}
+
+impl TxResponse {
+    pub fn is_success(&self) -> bool {
+        self.code == 0
+    }
+
+    /**
+       Find all events of the given type, e.g. `"send_packet"` or
+       `"write_acknowledgement"`.
+    */
+    pub fn events_of_type<'a>(&'a self, kind: &'a str) -> impl Iterator<Item = &'a TxEvent> {
+        self.events.iter().filter(move |event| event.kind == kind)
+    }
+}
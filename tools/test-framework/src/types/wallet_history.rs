@@ -0,0 +1,84 @@
+/*!
+   Type definitions for a wallet's paginated, decoded transfer history.
+*/
+
+use crate::ibc::denom::Denom;
+use crate::types::tagged::MonoTagged;
+use crate::types::wallet::WalletAddress;
+
+/**
+   A single decoded transfer, either sent or received by the wallet the
+   history was queried for. Untagged: this is what [`ChainDriver`](crate::chain::driver::ChainDriver)
+   decodes off the wire; [`TaggedChainDriverExt::query_wallet_history`](crate::chain::tagged::TaggedChainDriverExt::query_wallet_history)
+   tags the denom with the chain it queried.
+*/
+#[derive(Debug, Clone)]
+pub struct WalletTransfer {
+    pub height: u64,
+    pub tx_hash: String,
+    pub counterparty: WalletAddress,
+    pub denom: Denom,
+    pub amount: u64,
+}
+
+/**
+   An opaque continuation cursor for [`query_wallet_history`](crate::chain::tagged::TaggedChainDriverExt::query_wallet_history),
+   so that tests against long-lived chains don't have to load the whole
+   history at once.
+*/
+#[derive(Debug, Clone)]
+pub struct WalletHistoryCursor(pub String);
+
+/**
+   One page of a wallet's transfer history.
+*/
+#[derive(Debug, Clone)]
+pub struct WalletHistoryPage {
+    pub transfers: Vec<WalletTransfer>,
+    pub next_cursor: Option<WalletHistoryCursor>,
+}
+
+/**
+   A single decoded transfer with its denom tagged to the `Chain` it was
+   queried on.
+*/
+#[derive(Debug, Clone)]
+pub struct TaggedWalletTransfer<Chain> {
+    pub height: u64,
+    pub tx_hash: String,
+    pub counterparty: WalletAddress,
+    pub denom: MonoTagged<Chain, Denom>,
+    pub amount: u64,
+}
+
+/**
+   One page of a wallet's transfer history, with each transfer's denom
+   tagged to the `Chain` it was queried on.
+*/
+#[derive(Debug, Clone)]
+pub struct TaggedWalletHistoryPage<Chain> {
+    pub transfers: Vec<TaggedWalletTransfer<Chain>>,
+    pub next_cursor: Option<WalletHistoryCursor>,
+}
+
+impl WalletHistoryPage {
+    /**
+       Tag each transfer's denom with `Chain`.
+    */
+    pub fn tagged<Chain>(self) -> TaggedWalletHistoryPage<Chain> {
+        TaggedWalletHistoryPage {
+            transfers: self
+                .transfers
+                .into_iter()
+                .map(|transfer| TaggedWalletTransfer {
+                    height: transfer.height,
+                    tx_hash: transfer.tx_hash,
+                    counterparty: transfer.counterparty,
+                    denom: MonoTagged::new(transfer.denom),
+                    amount: transfer.amount,
+                })
+                .collect(),
+            next_cursor: self.next_cursor,
+        }
+    }
+}